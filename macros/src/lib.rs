@@ -11,7 +11,7 @@ use proc_macro::TokenStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::synom::Synom;
-use syn::{Expr, ExprArray, Ident};
+use syn::ExprArray;
 
 struct Mat {
     rows: Punctuated<ExprArray, Token![,]>,
@@ -29,7 +29,6 @@ pub fn mat(input: TokenStream) -> TokenStream {
     let mat: Mat = syn::parse(input).unwrap();
 
     // check consistent number of columns
-    let nrows = mat.rows.len();
     let ncols = mat.rows.iter().next().expect("BUG: zero rows").elems.len();
 
     for row in mat.rows.iter() {
@@ -43,14 +42,10 @@ pub fn mat(input: TokenStream) -> TokenStream {
         }
     }
 
-    let size = nrows * ncols;
-    let elems: Vec<&Expr> = mat.rows.iter().flat_map(|row| row.elems.iter()).collect();
+    let rows = mat.rows.iter();
 
-    let nrows_ty = Ident::from(format!("U{}", nrows));
-    let ncols_ty = Ident::from(format!("U{}", ncols));
-
-    quote!(unsafe {
+    quote!({
         extern crate mat;
-        mat::Mat::<_, [_; #size], mat::typenum::#nrows_ty, mat::typenum::#ncols_ty>::new([#(#elems,)*])
+        mat::Mat::new([#(#rows),*])
     }).into()
 }
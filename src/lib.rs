@@ -5,8 +5,8 @@
 //! for this library are `no_std` programs where a memory allocator is not available.
 //!
 //! Since the matrices are statically allocated the dimensions of the matrix are stored in the type
-//! system and used to prevent invalid operations (e.g. adding a 3x4 matrix to a 4x3 matrix) at
-//! compile time.
+//! system (as `const` generics) and used to prevent invalid operations (e.g. adding a 3x4 matrix to
+//! a 4x3 matrix) at compile time.
 //!
 //! For performance reasons all operations, except for the indexing `get` method, are lazy and
 //! perform no actual computation. An expression like `a * b + c;` simply builds an *expression
@@ -55,32 +55,16 @@
 //! If you are looking for such features check out the [`ndarray`] crate.
 //!
 //! [`ndarray`]: https://crates.io/crates/ndarray
-//!
-//! # Development status
-//!
-//! This library is unlikely to see much development until support for [const generics] lands in the
-//! compiler.
-//!
-//! [const generics]: https://github.com/rust-lang/rust/issues/44580
 
 #![deny(missing_docs)]
 #![deny(warnings)]
 #![no_std]
 
-extern crate generic_array;
-
 pub mod traits;
 
-use core::marker::PhantomData;
 use core::{fmt, ops};
 
-pub use generic_array::typenum::consts;
-use generic_array::typenum::consts::U1;
-pub use generic_array::typenum::Quot as __Quot;
-use generic_array::typenum::{Prod, Unsigned};
-use generic_array::{ArrayLength, GenericArray};
-
-use traits::{Matrix, UnsafeGet, Zero};
+use traits::{Matrix, One, Scalar, UnsafeGet, Zero};
 
 /// Macro to construct a `Mat`rix
 ///
@@ -104,89 +88,219 @@ use traits::{Matrix, UnsafeGet, Zero};
 /// ```
 #[macro_export]
 macro_rules! mat {
-    ($ty:ty, [$([$($e:expr),*],)+]) => ({
-        extern crate core;
-
-        type NROWS = __nrows!($crate::consts::U0; [ $([ $($e),* ],)* ] );
-        type NELEMS = __nelems!($crate::consts::U0; [ $( $($e),* ,)* ]);
-        type NCOLS = $crate::__Quot<NELEMS, NROWS>;
-
-        unsafe {
-            core::mem::transmute::<_, $crate::Mat<$ty, NROWS, NCOLS>>(
-                [ $( $({ let e: $ty = $e; e }),* ),* ]
-            )
-        }
-    })
-}
-
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __nrows {
-    ($i:ty; []) => {
-        $i
-    };
-
-    ($i:ty; [ [$($head:expr),*], $( [$($tail:expr),*] ,)*]) => {
-        __nrows!($crate::__Inc<$i>; [$( [$($tail),*] ,)*])
+    ($ty:ty, [$([$($e:expr),*],)+]) => {
+        $crate::Mat::new([ $( [ $( { let e: $ty = $e; e } ),* ] ),* ])
     };
 }
 
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __nelems {
-    ($i:ty; []) => {
-        $i
-    };
-    ($i:ty; [$head:expr, $($tail:expr,)*]) => {
-        __nelems!($crate::__Inc<$i>; [ $($tail,)* ])
-    };
+/// Statically allocated (row major order) matrix
+#[derive(Clone)]
+pub struct Mat<T, const NROWS: usize, const NCOLS: usize> {
+    buffer: [[T; NCOLS]; NROWS],
 }
 
-#[doc(hidden)]
-pub type __Inc<T> = generic_array::typenum::Sum<T, U1>;
+impl<T, const NROWS: usize, const NCOLS: usize> Mat<T, NROWS, NCOLS> {
+    /// Creates a new matrix from its row-major buffer
+    pub fn new(buffer: [[T; NCOLS]; NROWS]) -> Self {
+        Mat { buffer }
+    }
+}
 
-/// Row view into a `Mat`rix
-pub struct Row<T, NCOLS>
+impl<T, const NROWS: usize, const NCOLS: usize> Mat<T, NROWS, NCOLS>
 where
-    NCOLS: ArrayLength<T>,
+    T: Zero,
 {
-    buffer: GenericArray<T, NCOLS>,
+    /// Creates a matrix filled with zeros
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a: Mat<i32, 2, 2> = Mat::zeros();
+    ///
+    ///     assert_eq!((&a).get(0, 0), 0);
+    ///     assert_eq!((&a).get(1, 1), 0);
+    /// }
+    /// ```
+    pub fn zeros() -> Self {
+        Mat {
+            buffer: core::array::from_fn(|_| core::array::from_fn(|_| T::zero())),
+        }
+    }
 }
 
-impl<T, NCOLS> ops::Index<usize> for Row<T, NCOLS>
+impl<T, const NROWS: usize, const NCOLS: usize> Mat<T, NROWS, NCOLS>
 where
-    NCOLS: ArrayLength<T>,
+    T: Clone,
 {
-    type Output = T;
+    /// Creates a matrix where every element equals `x`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a: Mat<i32, 2, 2> = Mat::from_element(5);
+    ///
+    ///     assert_eq!((&a).get(0, 1), 5);
+    ///     assert_eq!((&a).get(1, 0), 5);
+    /// }
+    /// ```
+    pub fn from_element(x: T) -> Self {
+        Mat {
+            buffer: core::array::from_fn(|_| core::array::from_fn(|_| x.clone())),
+        }
+    }
+}
 
-    fn index(&self, c: usize) -> &T {
-        assert!(c < NCOLS::to_usize());
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Zero + One,
+{
+    /// Creates the identity matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a: Mat<i32, 3, 3> = Mat::identity();
+    ///
+    ///     assert_eq!((&a).get(0, 0), 1);
+    ///     assert_eq!((&a).get(1, 1), 1);
+    ///     assert_eq!((&a).get(0, 1), 0);
+    /// }
+    /// ```
+    pub fn identity() -> Self {
+        let mut m = Self::zeros();
+
+        for i in 0..N {
+            m.buffer[i][i] = T::one();
+        }
 
-        unsafe { self.buffer.get_unchecked(c) }
+        m
     }
 }
 
-impl<T, NCOLS> ops::IndexMut<usize> for Row<T, NCOLS>
+impl<T, const NROWS: usize> Mat<T, NROWS, 1>
 where
-    NCOLS: ArrayLength<T>,
+    T: Zero + One,
 {
-    fn index_mut(&mut self, c: usize) -> &mut T {
-        assert!(c < NCOLS::to_usize());
+    /// Returns the `i`-th standard basis (column) vector
+    ///
+    /// # Panics
+    ///
+    /// This operation panics if `i` exceeds the vector dimensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a: Mat<i32, 3, 1> = Mat::e(1);
+    ///
+    ///     assert_eq!((&a).get(0, 0), 0);
+    ///     assert_eq!((&a).get(1, 0), 1);
+    ///     assert_eq!((&a).get(2, 0), 0);
+    /// }
+    /// ```
+    pub fn e(i: usize) -> Self {
+        assert!(i < NROWS);
+
+        let mut v = Self::zeros();
+        v.buffer[i][0] = T::one();
+        v
+    }
+}
 
-        unsafe { self.buffer.get_unchecked_mut(c) }
+impl<T, const NCOLS: usize> Mat<T, 1, NCOLS>
+where
+    T: Zero + One,
+{
+    /// Returns the `i`-th standard basis row vector
+    ///
+    /// # Panics
+    ///
+    /// This operation panics if `i` exceeds the vector dimensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a: Mat<i32, 1, 3> = Mat::e_row(1);
+    ///
+    ///     assert_eq!((&a).get(0, 0), 0);
+    ///     assert_eq!((&a).get(0, 1), 1);
+    ///     assert_eq!((&a).get(0, 2), 0);
+    /// }
+    /// ```
+    pub fn e_row(i: usize) -> Self {
+        assert!(i < NCOLS);
+
+        let mut v = Self::zeros();
+        v.buffer[0][i] = T::one();
+        v
     }
 }
 
-/// Statically allocated (row major order) matrix
-#[derive(Clone)]
-pub struct Mat<T, NROWS, NCOLS>
+impl<T, const NROWS: usize, const NCOLS: usize> Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS>,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
+    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Scalar + Zero,
 {
-    buffer: GenericArray<T, Prod<NROWS, NCOLS>>,
-    _nrows: PhantomData<NROWS>,
-    _ncols: PhantomData<NCOLS>,
+    /// Computes `self = beta * self + alpha * (a * b)`, writing into the raw buffer in place
+    ///
+    /// This is useful in hot loops where `a` and `b` change on every iteration but `self` is
+    /// reused as the output, avoiding both the allocation and the tree walk that building and
+    /// `eval`-ing `a * b` on every iteration would incur
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [[1, 2],]);
+    ///     let b = mat!(i32, [[3], [4],]);
+    ///
+    ///     let mut c: Mat<i32, 1, 1> = Mat::zeros();
+    ///     c.gemm(1, &a, &b, 0);
+    ///
+    ///     assert_eq!((&c).get(0, 0), 11);
+    /// }
+    /// ```
+    pub fn gemm<A, B, const K: usize>(&mut self, alpha: T, a: A, b: B, beta: T)
+    where
+        A: Matrix<Elem = T, NROWS = traits::Dim<NROWS>, NCOLS = traits::Dim<K>>,
+        B: Matrix<Elem = T, NROWS = traits::Dim<K>, NCOLS = traits::Dim<NCOLS>>,
+    {
+        for i in 0..NROWS {
+            for j in 0..NCOLS {
+                let mut sum = T::zero();
+                for k in 0..K {
+                    sum = sum + a.get(i, k) * b.get(k, j);
+                }
+
+                let old = self.buffer[i][j].inlined_clone();
+                self.buffer[i][j] = beta.inlined_clone() * old + alpha.inlined_clone() * sum;
+            }
+        }
+    }
 }
 
 /// The product of two matrices
@@ -203,94 +317,120 @@ pub struct Sum<L, R> {
     r: R,
 }
 
+/// The difference of two matrices
+///
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate mat;
+///
+/// use mat::traits::Matrix;
+///
+/// fn main() {
+///     let a = mat!(i32, [[3, 4],]);
+///     let b = mat!(i32, [[1, 2],]);
+///
+///     let c = &a - &b;
+///
+///     assert_eq!(c.get(0, 0), 2);
+///     assert_eq!(c.get(0, 1), 2);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Difference<L, R> {
+    l: L,
+    r: R,
+}
+
 /// The transpose of a matrix
 #[derive(Clone, Copy)]
 pub struct Transpose<M> {
     m: M,
 }
 
-impl<T, NROWS, NCOLS> fmt::Debug for Mat<T, NROWS, NCOLS>
+/// The negation of a matrix
+#[derive(Clone, Copy)]
+pub struct Neg<M> {
+    m: M,
+}
+
+/// The element-wise application of a closure to a single matrix
+#[derive(Clone, Copy)]
+pub struct CwiseUnary<M, F> {
+    m: M,
+    f: F,
+}
+
+/// The element-wise application of a closure to a pair of matrices
+#[derive(Clone, Copy)]
+pub struct CwiseBinary<L, R, F> {
+    l: L,
+    r: R,
+    f: F,
+}
+
+impl<T, const NROWS: usize, const NCOLS: usize> fmt::Debug for Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS>,
-    NCOLS: Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
     T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_list()
-            .entries(self.buffer.chunks(NCOLS::to_usize()))
-            .finish()
+        f.debug_list().entries(self.buffer.iter()).finish()
     }
 }
 
-impl<'a, T, NROWS, NCOLS> Matrix for &'a Mat<T, NROWS, NCOLS>
+impl<T, const NROWS: usize, const NCOLS: usize> Matrix for &Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS> + Unsigned,
-    NCOLS: Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
-    T: Copy,
+    T: Scalar,
 {
-    type NROWS = NROWS;
-    type NCOLS = NCOLS;
+    type NROWS = traits::Dim<NROWS>;
+    type NCOLS = traits::Dim<NCOLS>;
 }
 
-impl<'a, T, NROWS, NCOLS> UnsafeGet for &'a Mat<T, NROWS, NCOLS>
+impl<T, const NROWS: usize, const NCOLS: usize> UnsafeGet for &Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS> + Unsigned,
-    NCOLS: Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
-    T: Copy,
+    T: Scalar,
 {
     type Elem = T;
 
     unsafe fn unsafe_get(self, r: usize, c: usize) -> T {
-        *self.buffer.get_unchecked(r * NCOLS::to_usize() + c)
+        self.buffer[r][c].inlined_clone()
     }
 }
 
-impl<T, NROWS, NCOLS> ops::Index<usize> for Mat<T, NROWS, NCOLS>
-where
-    NROWS: ops::Mul<NCOLS> + Unsigned,
-    NCOLS: ArrayLength<T> + Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
-{
-    type Output = Row<T, NCOLS>;
+impl<T, const NROWS: usize, const NCOLS: usize> ops::Index<usize> for Mat<T, NROWS, NCOLS> {
+    type Output = [T; NCOLS];
 
-    fn index(&self, r: usize) -> &Row<T, NCOLS> {
-        assert!(r < NROWS::to_usize());
+    fn index(&self, r: usize) -> &[T; NCOLS] {
+        &self.buffer[r]
+    }
+}
 
-        unsafe {
-            &*(self.buffer.get_unchecked(r * NCOLS::to_usize()) as *const _ as *const Row<_, _>)
-        }
+impl<T, const NROWS: usize, const NCOLS: usize> ops::IndexMut<usize> for Mat<T, NROWS, NCOLS> {
+    fn index_mut(&mut self, r: usize) -> &mut [T; NCOLS] {
+        &mut self.buffer[r]
     }
 }
 
-impl<T, NROWS, NCOLS> ops::IndexMut<usize> for Mat<T, NROWS, NCOLS>
+impl<'a, T, const NROWS: usize, const NCOLS: usize, R> ops::Mul<R> for &'a Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS> + Unsigned,
-    NCOLS: ArrayLength<T> + Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
+    R: Matrix<NROWS = traits::Dim<NCOLS>>,
 {
-    fn index_mut(&mut self, r: usize) -> &mut Row<T, NCOLS> {
-        assert!(r < NROWS::to_usize());
+    type Output = Product<&'a Mat<T, NROWS, NCOLS>, R>;
 
-        unsafe {
-            &mut *(self.buffer.get_unchecked_mut(r * NCOLS::to_usize()) as *mut _ as *mut Row<_, _>)
-        }
+    fn mul(self, rhs: R) -> Self::Output {
+        Product { l: self, r: rhs }
     }
 }
 
-impl<'a, T, NROWS, NCOLS, R> ops::Mul<R> for &'a Mat<T, NROWS, NCOLS>
+impl<'a, T, const NROWS: usize, const NCOLS: usize, RHS> ops::Sub<RHS> for &'a Mat<T, NROWS, NCOLS>
 where
-    NROWS: ops::Mul<NCOLS>,
-    NCOLS: Unsigned,
-    Prod<NROWS, NCOLS>: ArrayLength<T>,
-    R: Matrix<NROWS = NCOLS>,
+    RHS: Matrix<NROWS = traits::Dim<NROWS>, NCOLS = traits::Dim<NCOLS>>,
 {
-    type Output = Product<&'a Mat<T, NROWS, NCOLS>, R>;
+    type Output = Difference<&'a Mat<T, NROWS, NCOLS>, RHS>;
 
-    fn mul(self, rhs: R) -> Self::Output {
-        Product { l: self, r: rhs }
+    fn sub(self, rhs: RHS) -> Self::Output {
+        Difference { l: self, r: rhs }
     }
 }
 
@@ -333,11 +473,50 @@ where
     }
 }
 
+impl<L, RHS> ops::Sub<RHS> for Transpose<L>
+where
+    L: Matrix,
+    RHS: Matrix<NROWS = L::NCOLS, NCOLS = L::NROWS>,
+{
+    type Output = Difference<Transpose<L>, RHS>;
+
+    fn sub(self, rhs: RHS) -> Self::Output {
+        Difference { l: self, r: rhs }
+    }
+}
+
+impl<M> Matrix for Neg<M>
+where
+    M: Matrix,
+    M::Elem: ops::Neg<Output = M::Elem>,
+{
+    type NROWS = M::NROWS;
+    type NCOLS = M::NCOLS;
+}
+
+impl<M> UnsafeGet for Neg<M>
+where
+    M: Matrix,
+    M::Elem: ops::Neg<Output = M::Elem>,
+{
+    type Elem = M::Elem;
+
+    unsafe fn unsafe_get(self, r: usize, c: usize) -> M::Elem {
+        -self.m.unsafe_get(r, c)
+    }
+}
+
+impl<M> traits::Neg for M
+where
+    M: Matrix,
+{
+}
+
 impl<L, R, T> Matrix for Product<L, R>
 where
     L: Matrix<Elem = T>,
     R: Matrix<Elem = T>,
-    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Copy + Zero,
+    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Scalar + Zero,
 {
     type NROWS = L::NROWS;
     type NCOLS = R::NCOLS;
@@ -347,7 +526,7 @@ impl<T, L, R> UnsafeGet for Product<L, R>
 where
     L: Matrix<Elem = T>,
     R: Matrix<Elem = T>,
-    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Copy + Zero,
+    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Scalar + Zero,
 {
     type Elem = T;
 
@@ -360,6 +539,49 @@ where
     }
 }
 
+impl<T, L, R> Product<L, R>
+where
+    L: Matrix<Elem = T>,
+    R: Matrix<Elem = T>,
+    T: ops::Add<T, Output = T> + ops::Mul<T, Output = T> + Scalar + Zero,
+{
+    /// Writes this product into a preallocated output matrix
+    ///
+    /// This avoids both the allocation that `eval` performs and, when `out` is reused across
+    /// iterations, the redundant tree walk that repeated `get` calls on the same product incur
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::Matrix;
+    /// use mat::Mat;
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [[1, 2],]);
+    ///     let b = mat!(i32, [[3], [4],]);
+    ///
+    ///     let mut c: Mat<i32, 1, 1> = Mat::zeros();
+    ///     (&a * &b).mul_to(&mut c);
+    ///
+    ///     assert_eq!((&c).get(0, 0), 11);
+    /// }
+    /// ```
+    pub fn mul_to<const NROWS: usize, const NCOLS: usize>(self, out: &mut Mat<T, NROWS, NCOLS>)
+    where
+        L: Matrix<NROWS = traits::Dim<NROWS>>,
+        R: Matrix<NCOLS = traits::Dim<NCOLS>>,
+    {
+        for r in 0..NROWS {
+            for c in 0..NCOLS {
+                out.buffer[r][c] = unsafe { self.unsafe_get(r, c) };
+            }
+        }
+    }
+}
+
 impl<L, R, RHS> ops::Add<RHS> for Product<L, R>
 where
     L: Matrix,
@@ -373,11 +595,24 @@ where
     }
 }
 
+impl<L, R, RHS> ops::Sub<RHS> for Product<L, R>
+where
+    L: Matrix,
+    R: Matrix,
+    RHS: Matrix<NROWS = L::NROWS, NCOLS = R::NCOLS>,
+{
+    type Output = Difference<Product<L, R>, RHS>;
+
+    fn sub(self, rhs: RHS) -> Self::Output {
+        Difference { l: self, r: rhs }
+    }
+}
+
 impl<T, L, R> Matrix for Sum<L, R>
 where
     L: Matrix<Elem = T>,
     R: Matrix<Elem = T>,
-    T: ops::Add<T, Output = T> + Copy,
+    T: ops::Add<T, Output = T> + Scalar,
 {
     type NROWS = L::NROWS;
     type NCOLS = L::NCOLS;
@@ -387,7 +622,7 @@ impl<T, L, R> UnsafeGet for Sum<L, R>
 where
     L: Matrix<Elem = T>,
     R: Matrix<Elem = T>,
-    T: ops::Add<T, Output = T> + Copy,
+    T: ops::Add<T, Output = T> + Scalar,
 {
     type Elem = T;
 
@@ -395,3 +630,100 @@ where
         self.l.unsafe_get(r, c) + self.r.unsafe_get(r, c)
     }
 }
+
+impl<L, R, RHS> ops::Sub<RHS> for Sum<L, R>
+where
+    L: Matrix,
+    R: Matrix,
+    RHS: Matrix<NROWS = L::NROWS, NCOLS = L::NCOLS>,
+{
+    type Output = Difference<Sum<L, R>, RHS>;
+
+    fn sub(self, rhs: RHS) -> Self::Output {
+        Difference { l: self, r: rhs }
+    }
+}
+
+impl<T, L, R> Matrix for Difference<L, R>
+where
+    L: Matrix<Elem = T>,
+    R: Matrix<Elem = T>,
+    T: ops::Sub<T, Output = T> + Scalar,
+{
+    type NROWS = L::NROWS;
+    type NCOLS = L::NCOLS;
+}
+
+impl<T, L, R> UnsafeGet for Difference<L, R>
+where
+    L: Matrix<Elem = T>,
+    R: Matrix<Elem = T>,
+    T: ops::Sub<T, Output = T> + Scalar,
+{
+    type Elem = T;
+
+    unsafe fn unsafe_get(self, r: usize, c: usize) -> T {
+        self.l.unsafe_get(r, c) - self.r.unsafe_get(r, c)
+    }
+}
+
+impl<L, R, RHS> ops::Sub<RHS> for Difference<L, R>
+where
+    L: Matrix,
+    R: Matrix,
+    RHS: Matrix<NROWS = L::NROWS, NCOLS = L::NCOLS>,
+{
+    type Output = Difference<Difference<L, R>, RHS>;
+
+    fn sub(self, rhs: RHS) -> Self::Output {
+        Difference { l: self, r: rhs }
+    }
+}
+
+impl<M, F, O> Matrix for CwiseUnary<M, F>
+where
+    M: Matrix,
+    F: Copy + Fn(M::Elem) -> O,
+    O: Scalar,
+{
+    type NROWS = M::NROWS;
+    type NCOLS = M::NCOLS;
+}
+
+impl<M, F, O> UnsafeGet for CwiseUnary<M, F>
+where
+    M: Matrix,
+    F: Copy + Fn(M::Elem) -> O,
+    O: Scalar,
+{
+    type Elem = O;
+
+    unsafe fn unsafe_get(self, r: usize, c: usize) -> O {
+        (self.f)(self.m.unsafe_get(r, c))
+    }
+}
+
+impl<L, R, F, O> Matrix for CwiseBinary<L, R, F>
+where
+    L: Matrix,
+    R: Matrix<NROWS = L::NROWS, NCOLS = L::NCOLS>,
+    F: Copy + Fn(L::Elem, R::Elem) -> O,
+    O: Scalar,
+{
+    type NROWS = L::NROWS;
+    type NCOLS = L::NCOLS;
+}
+
+impl<L, R, F, O> UnsafeGet for CwiseBinary<L, R, F>
+where
+    L: Matrix,
+    R: Matrix<NROWS = L::NROWS, NCOLS = L::NCOLS>,
+    F: Copy + Fn(L::Elem, R::Elem) -> O,
+    O: Scalar,
+{
+    type Elem = O;
+
+    unsafe fn unsafe_get(self, r: usize, c: usize) -> O {
+        (self.f)(self.l.unsafe_get(r, c), self.r.unsafe_get(r, c))
+    }
+}
@@ -1,6 +1,29 @@
 //! Traits
 
-use typenum::Unsigned;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// A type-level representation of a `usize`
+///
+/// `Matrix::NROWS` and `Matrix::NCOLS` are encoded as `Dim<N>` rather than as plain `usize`
+/// associated consts so that two different `Matrix` implementors can be compared for shape
+/// compatibility via associated *type* equality bounds (e.g. `R: Matrix<NROWS = Self::NROWS>`),
+/// which is stable. The analogous associated *const* equality bound (`R: Matrix<NROWS = N>`)
+/// would require the unstable `associated_const_equality` feature.
+pub struct Dim<const N: usize>;
+
+/// Maps a [`Dim`] back to the `usize` it represents
+pub trait DimVal {
+    /// The `usize` this `Dim` stands for
+    const VALUE: usize;
+}
+
+impl<const N: usize> DimVal for Dim<N> {
+    const VALUE: usize = N;
+}
+
+/// The backing storage of a `NROWS`-by-`NCOLS` matrix, row-major
+type Buffer<T, const NROWS: usize, const NCOLS: usize> = [[T; NCOLS]; NROWS];
 
 /// The transpose operation
 pub trait Transpose: Copy {
@@ -10,12 +33,41 @@ pub trait Transpose: Copy {
     }
 }
 
+/// The negation operation
+pub trait Neg: Copy {
+    /// Negates the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::{Matrix, Neg};
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [
+    ///         [1, -2],
+    ///         [3, 4],
+    ///     ]);
+    ///
+    ///     let b = (&a).neg();
+    ///
+    ///     assert_eq!(b.get(0, 0), -1);
+    ///     assert_eq!(b.get(0, 1), 2);
+    /// }
+    /// ```
+    fn neg(self) -> super::Neg<Self> {
+        super::Neg { m: self }
+    }
+}
+
 /// A matrix
 pub trait Matrix: UnsafeGet {
     /// Number of rows
-    type NROWS: Unsigned;
+    type NROWS: DimVal;
     /// Number of columns
-    type NCOLS: Unsigned;
+    type NCOLS: DimVal;
 
     /// Returns the element at row `r` and column `c`
     ///
@@ -30,7 +82,10 @@ pub trait Matrix: UnsafeGet {
 
     /// Returns the size of the matrix
     fn size(self) -> (usize, usize) {
-        (Self::NROWS::to_usize(), Self::NCOLS::to_usize())
+        (
+            <Self::NROWS as DimVal>::VALUE,
+            <Self::NCOLS as DimVal>::VALUE,
+        )
     }
 
     /// Returns the number of rows of the matrix
@@ -42,19 +97,167 @@ pub trait Matrix: UnsafeGet {
     fn ncols(self) -> usize {
         self.size().1
     }
+
+    /// Applies `f` to every element of the matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::Matrix;
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [
+    ///         [1, 2],
+    ///         [3, 4],
+    ///     ]);
+    ///
+    ///     let b = (&a).map(|x| x * 2);
+    ///
+    ///     assert_eq!(b.get(0, 0), 2);
+    ///     assert_eq!(b.get(1, 1), 8);
+    /// }
+    /// ```
+    fn map<F, O>(self, f: F) -> super::CwiseUnary<Self, F>
+    where
+        F: Copy + Fn(Self::Elem) -> O,
+    {
+        super::CwiseUnary { m: self, f }
+    }
+
+    /// Applies `f` to every pair of elements of `self` and `rhs`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::Matrix;
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [[1, 2],]);
+    ///     let b = mat!(i32, [[3, 4],]);
+    ///
+    ///     let c = (&a).zip_with(&b, |x, y| x + y);
+    ///
+    ///     assert_eq!(c.get(0, 0), 4);
+    ///     assert_eq!(c.get(0, 1), 6);
+    /// }
+    /// ```
+    fn zip_with<R, F, O>(self, rhs: R, f: F) -> super::CwiseBinary<Self, R, F>
+    where
+        R: Matrix<NROWS = Self::NROWS, NCOLS = Self::NCOLS>,
+        F: Copy + Fn(Self::Elem, R::Elem) -> O,
+    {
+        super::CwiseBinary {
+            l: self,
+            r: rhs,
+            f,
+        }
+    }
+
+    /// Materializes this expression tree into an owned matrix
+    ///
+    /// This walks every element of the matrix exactly once, so it is preferable to repeated
+    /// calls to `get` when the same (possibly deep) expression tree is going to be read more
+    /// than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate mat;
+    ///
+    /// use mat::traits::Matrix;
+    ///
+    /// fn main() {
+    ///     let a = mat!(i32, [[1, 2],]);
+    ///     let b = mat!(i32, [[3, 4],]);
+    ///
+    ///     let c = (&a - &b).eval();
+    ///
+    ///     assert_eq!(c[0], [-2, -2]);
+    /// }
+    /// ```
+    fn eval<const NROWS: usize, const NCOLS: usize>(self) -> super::Mat<Self::Elem, NROWS, NCOLS>
+    where
+        Self: Matrix<NROWS = Dim<NROWS>, NCOLS = Dim<NCOLS>>,
+    {
+        let nrows = self.nrows();
+        let ncols = self.ncols();
+
+        let mut buffer: MaybeUninit<Buffer<Self::Elem, NROWS, NCOLS>> = MaybeUninit::uninit();
+        let ptr = buffer.as_mut_ptr() as *mut Self::Elem;
+
+        for r in 0..nrows {
+            for c in 0..ncols {
+                unsafe { ptr::write(ptr.add(r * ncols + c), self.unsafe_get(r, c)) }
+            }
+        }
+
+        super::Mat::new(unsafe { buffer.assume_init() })
+    }
 }
 
 /// Unsafe indexing
 // NOTE(`: Copy`) this bound is a lint against expression trees that take ownership of `Mat`
 pub trait UnsafeGet: Copy {
     /// The matrix element type
-    // NOTE(`: Copy`) let's narrow down the problem to matrices that contain only primitive types
-    type Elem: Copy;
+    type Elem: Scalar;
 
     /// Returns the element at row `r` and column `c` with performing bounds checks
+    ///
+    /// # Safety
+    ///
+    /// `r` and `c` must be within the matrix dimensions; callers should go through [`Matrix::get`]
+    /// (which bounds-checks before delegating here) unless they have already checked the bounds
+    /// themselves.
     unsafe fn unsafe_get(self, r: usize, c: usize) -> Self::Elem;
 }
 
+/// Types that can be used as matrix elements
+///
+/// This is blanket implemented for every `Copy` type; types that are only `Clone` (bignums,
+/// fixed-point numbers, etc.) can implement it directly to opt into being stored in a `Mat`.
+///
+/// # Example
+///
+/// ```
+/// use mat::traits::{Matrix, Scalar};
+/// use mat::Mat;
+///
+/// #[derive(Clone)]
+/// struct BigNum(i64);
+///
+/// impl Scalar for BigNum {
+///     fn inlined_clone(&self) -> Self {
+///         self.clone()
+///     }
+/// }
+///
+/// fn main() {
+///     let a: Mat<BigNum, 1, 1> = Mat::new([[BigNum(42)]]);
+///
+///     assert_eq!((&a).get(0, 0).0, 42);
+/// }
+/// ```
+pub trait Scalar: Clone {
+    /// Clones `self`
+    fn inlined_clone(&self) -> Self;
+}
+
+impl<T> Scalar for T
+where
+    T: Copy,
+{
+    fn inlined_clone(&self) -> T {
+        *self
+    }
+}
+
 /// Types that have a "zero" value
 pub trait Zero {
     /// Returns the value of this type that represents the number zero
@@ -86,3 +289,35 @@ impl Zero for f64 {
         0.
     }
 }
+
+/// Types that have a "one" value
+pub trait One {
+    /// Returns the value of this type that represents the number one
+    fn one() -> Self;
+}
+
+macro_rules! one {
+    ($($ty:ty),+) => {
+        $(
+            impl One for $ty {
+                fn one() -> Self {
+                    1
+                }
+            }
+        )+
+    }
+}
+
+one!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl One for f32 {
+    fn one() -> f32 {
+        1.
+    }
+}
+
+impl One for f64 {
+    fn one() -> f64 {
+        1.
+    }
+}